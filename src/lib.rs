@@ -63,6 +63,52 @@ use beard::beard;
 # let output = render().unwrap();
 ```
 
+You can also pass a format specifier, the same you would pass to
+[`std::format`], after a `:`, to control things like padding,
+alignment or precision. As with `if` and `for`, wrap the expression
+in parentheses so the macro knows where it ends.
+
+```
+use beard::beard;
+# use std::io::Write as _;
+#
+# fn render() -> Result<String, std::io::Error> {
+#    let mut output = Vec::new();
+    let ratio = 0.5;
+    beard! {
+        output,
+        { (ratio) : 5.2 } "\n"
+        { (42) : 08x } "\n"
+        { (ratio * 2.0) : 5.2 } "\n"
+    };
+#    Ok(String::from_utf8(output).unwrap())
+# }
+# let output = render().unwrap();
+```
+
+## debugging values
+
+Sometimes you just want to dump a value's [`std::fmt::Debug`]
+representation, optionally alongside the expression that produced
+it, while prototyping a template or tracking down a bug.
+
+```
+use beard::beard;
+# use std::io::Write as _;
+#
+# fn render() -> Result<String, std::io::Error> {
+#    let mut output = Vec::new();
+    let values = vec![1, 2, 3];
+    beard! {
+        output,
+        [= values ] "\n"
+        { ? values } "\n"
+    };
+#    Ok(String::from_utf8(output).unwrap())
+# }
+# let output = render().unwrap();
+```
+
 ## serialising array of bytes
 
 In case the value is already an array and there is no need to run
@@ -139,6 +185,48 @@ use beard::beard;
 # let output = render().unwrap();
 ```
 
+## `match` statement
+
+Dispatching on an enum with `if let` arms quickly becomes
+repetitive and is not checked for exhaustiveness. Use `match`
+instead, it works just like a regular Rust `match` expression.
+
+```
+use beard::beard;
+# use std::io::Write as _;
+#
+# fn render() -> Result<String, std::io::Error> {
+#    let mut output = Vec::new();
+    let optional = Some("something");
+    let count = 3;
+    beard! {
+        output,
+        match (optional) {
+            Some(value) => {
+                "We have " { value } "\n"
+            }
+            None => {
+                "We have nothing\n"
+            }
+        }
+
+        match (count) {
+            0 => {
+                "Nothing left\n"
+            }
+            n if n > 1 => {
+                "Several left: " { n } "\n"
+            }
+            _ => {
+                "One left\n"
+            }
+        }
+    };
+#    Ok(String::from_utf8(output).unwrap())
+# }
+# let output = render().unwrap();
+```
+
 ## `for` loop, iterating on items
 
 Shall you need to print the items of a list or anything that
@@ -164,6 +252,52 @@ use beard::beard;
 # let output = render().unwrap();
 ```
 
+## `while` and `while let` loop
+
+Shall the number of iterations not be known up front from an
+[`std::iter::IntoIterator`], for example when pulling items from a
+queue until it is exhausted.
+
+```
+use beard::beard;
+# use std::io::Write as _;
+#
+# fn render() -> Result<String, std::io::Error> {
+#    let mut output = Vec::new();
+    let mut queue = vec![1, 2, 3];
+    beard! {
+        output,
+        while let Some(item) = (queue.pop()) {
+            "Popped " { item } "\n"
+        }
+    };
+#    Ok(String::from_utf8(output).unwrap())
+# }
+# let output = render().unwrap();
+```
+
+A plain `while (cond)` works the same way, for when the condition
+isn't a pattern match.
+
+```
+use beard::beard;
+# use std::io::Write as _;
+#
+# fn render() -> Result<String, std::io::Error> {
+#    let mut output = Vec::new();
+    let mut countdown = 3;
+    beard! {
+        output,
+        while (countdown > 0) {
+            "T-minus " { countdown } "\n"
+            || { countdown -= 1; }
+        }
+    };
+#    Ok(String::from_utf8(output).unwrap())
+# }
+# let output = render().unwrap();
+```
+
 # Example
 
 ```
@@ -203,7 +337,55 @@ Confirmation order about the following items:
 Your order will be ship to you once everything is ready.
 ```
 
+# `beard_string!` and `beard_fmt!`
+
+Not every call site has an [`std::io::Write`] handy. [`beard_string!`]
+builds the output internally and hands you back a [`String`], so it
+can be used directly in expression position.
+
+```
+use beard::beard_string;
+
+let name = "Arthur";
+let message = beard_string! {
+    "Hi " { name } "\n"
+};
+assert_eq!(message, "Hi Arthur\n");
+```
+
+[`beard_fmt!`] is the same idea but for [`std::fmt::Write`] sinks,
+such as the [`std::fmt::Formatter`] of a hand-written
+[`std::fmt::Display`] implementation.
+
+```
+use beard::beard_fmt;
+use std::fmt;
+
+struct Greeting<'a>(&'a str);
+
+impl<'a> fmt::Display for Greeting<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.0;
+        beard_fmt! {
+            f,
+            "Hi " { name } "\n"
+        }
+        Ok(())
+    }
+}
+
+assert_eq!(Greeting("Arthur").to_string(), "Hi Arthur\n");
+```
+
+Note that the raw byte array form (`[ { expr } ]`, see "serialising
+array of bytes" above) is not supported under [`beard_fmt!`]: a
+[`std::fmt::Write`] sink has no way to accept arbitrary bytes, only
+`&str`. Stick to the `Display`/`Debug`/format-spec interpolation
+forms in templates meant to run through [`beard_fmt!`].
+
 [`beard`]: ./macro.beard.html
+[`beard_string!`]: ./macro.beard_string.html
+[`beard_fmt!`]: ./macro.beard_fmt.html
 [mustache]: https://mustache.github.io/mustache.5.html
 */
 
@@ -221,6 +403,37 @@ macro_rules! beard {
     };
 }
 
+/// same as [`beard`] but builds the output internally and returns
+/// it as a [`String`], for use directly in expression position.
+#[macro_export]
+macro_rules! beard_string {
+    ($($any:tt)*) => {{
+        let mut output = Vec::new();
+        let render = |output: &mut Vec<u8>| -> Result<(), std::io::Error> {
+            use std::io::Write as _;
+            $crate::beard_internal!(output, $($any)*);
+            Ok(())
+        };
+        render(&mut output).expect("writing to a Vec<u8> does not fail");
+        String::from_utf8(output).expect("beard templates only produce valid utf-8")
+    }};
+}
+
+/// same as [`beard`] but targets any [`std::fmt::Write`] sink
+/// instead of an [`std::io::Write`] one, so it can be used inside
+/// a hand-written [`std::fmt::Display`] implementation.
+///
+/// the raw byte array interpolation form (`[ { expr } ]`) is not
+/// supported here, since a [`std::fmt::Write`] sink has no way to
+/// accept arbitrary bytes.
+#[macro_export]
+macro_rules! beard_fmt {
+    ($output:ident, $($any:tt)*) => {
+        use std::fmt::Write as _;
+        $crate::beard_internal!($output, $($any)*);
+    };
+}
+
 /// use this internal macro to hide the details of the macro away
 ///
 /// this is not really useful for the user documentation anyway.
@@ -245,7 +458,7 @@ macro_rules! beard_internal {
 
 
     ($output:ident, $text:literal $($any:tt)*) => {
-        $output.write_all($text.as_bytes())?;
+        write!($output, "{}", $text)?;
         $crate::beard_internal!($output, $($any)*);
     };
     ($output:ident, [ $statement:block ] $($any:tt)*) => {
@@ -254,12 +467,26 @@ macro_rules! beard_internal {
         )?;
         $crate::beard_internal!($output, $($any)*);
     };
-    ($output:ident, $statement:block $($any:tt)*) => {
-        $output.write_all(
-             $statement.to_string().as_bytes()
+    ($output:ident, [ = $statement:expr ] $($any:tt)*) => {
+        write!($output, "{} = {:?}", stringify!($statement), $statement)?;
+        $crate::beard_internal!($output, $($any)*);
+    };
+    ($output:ident, { ? $statement:expr } $($any:tt)*) => {
+        write!($output, "{:?}", $statement)?;
+        $crate::beard_internal!($output, $($any)*);
+    };
+    ($output:ident, { ( $statement:expr ) : $($spec:tt)+ } $($any:tt)*) => {
+        write!(
+            $output,
+            concat!("{:", $(stringify!($spec)),+ , "}"),
+            $statement
         )?;
         $crate::beard_internal!($output, $($any)*);
     };
+    ($output:ident, $statement:block $($any:tt)*) => {
+        write!($output, "{}", $statement)?;
+        $crate::beard_internal!($output, $($any)*);
+    };
 
     ($output:ident, if ( $condition:expr ) { $($statement:tt)+ } else { $($alternative:tt)+ } $($any:tt)*) => {
         if $condition {
@@ -282,6 +509,17 @@ macro_rules! beard_internal {
         $crate::beard_internal!($output, $($any)*);
     };
 
+    ($output:ident, match ( $condition:expr ) { $( $pattern:pat $(if $guard:expr)? => { $($statement:tt)* } )* } $($any:tt)*) => {
+        match $condition {
+            $(
+                $pattern $(if $guard)? => {
+                    $crate::beard_internal!($output, $($statement)*);
+                }
+            )*
+        }
+        $crate::beard_internal!($output, $($any)*);
+    };
+
     ($output:ident, for $value:pat in ($into_iter:expr) { $($statement:tt)+ } $($any:tt)*) => {
         for $value in $into_iter.into_iter() {
             #![allow(clippy::into_iter_on_ref, array_into_iter)]
@@ -289,6 +527,19 @@ macro_rules! beard_internal {
         }
         $crate::beard_internal!($output, $($any)*);
     };
+
+    ($output:ident, while let $pattern:pat = ( $value:expr ) { $($statement:tt)+ } $($any:tt)*) => {
+        while let $pattern = $value {
+            $crate::beard_internal!($output, $($statement)+);
+        }
+        $crate::beard_internal!($output, $($any)*);
+    };
+    ($output:ident, while ( $condition:expr ) { $($statement:tt)+ } $($any:tt)*) => {
+        while $condition {
+            $crate::beard_internal!($output, $($statement)+);
+        }
+        $crate::beard_internal!($output, $($any)*);
+    };
 }
 
 #[test]
@@ -297,24 +548,39 @@ fn test() {
 
     const EXPECTED: &str = r##"Variables can be formatted as follow: value.
 Statement works too: 3 (so you can do special formatting if you want).
+Formatted with a spec: 03.
+Formatted a compound expression: 00010.
+vec![1, 2, 3] = [1, 2, 3]
+[1, 2, 3]
  as bytes directly: value
 The length of the stuff is not null value
 Optional value set 1
 Optional value not set
+Several optionals: 2
 print thing: one
 print thing: two
+popped 2
+popped 1
+countdown 2
+countdown 1
 something custom"##;
 
     fn render() -> Result<String, std::io::Error> {
         let value = "value";
         let stuff = ["one", "two"];
         let optionals = [Some(1), None];
+        let mut queue = vec![1, 2];
+        let mut countdown = 2;
 
         let mut output = Vec::new();
         beard! {
             output,
             "Variables can be formatted as follow: " { value } ".\n"
             "Statement works too: " { 1 + 2} " (so you can do special formatting if you want).\n"
+            "Formatted with a spec: " { (3) : 02 } ".\n"
+            "Formatted a compound expression: " { ((2 + 3) * 2) : 05 } ".\n"
+            [= vec![1, 2, 3] ] "\n"
+            { ? vec![1, 2, 3] } "\n"
             if (value == "something") {
                 "This test is not rendered" { value }
             }
@@ -329,11 +595,25 @@ something custom"##;
 
 
             for optional in ( optionals ) {
-                if let Some(value) = ( optional ) {
-                    "Optional value set " { value } "\n"
+                match (optional) {
+                    Some(value) => {
+                        "Optional value set " { value } "\n"
+                    }
+                    None => {
+                        "Optional value not set\n"
+                    }
+                }
+            }
+
+            match (optionals.len()) {
+                0 => {
+                    "No optionals at all\n"
+                }
+                n if n > 1 => {
+                    "Several optionals: " { n } "\n"
                 }
-                if let None = (optional) {
-                    "Optional value not set\n"
+                _ => {
+                    "A single optional\n"
                 }
             }
 
@@ -342,6 +622,15 @@ something custom"##;
                 "print thing: " { thing } "\n"
             }
 
+            while let Some(item) = (queue.pop()) {
+                "popped " { item } "\n"
+            }
+
+            while (countdown > 0) {
+                "countdown " { countdown } "\n"
+                || { countdown -= 1; }
+            }
+
             | | { output.write_all(b"something custom")?; }
         };
         Ok(String::from_utf8(output).unwrap())